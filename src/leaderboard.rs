@@ -0,0 +1,145 @@
+/*
+Tracks each player's fewest guesses to win, as covered in the book's
+"Storing Keys with Associated Values in Hash Maps" chapter. Parsing and
+serialization are kept as plain functions over `&str`/`String` so they can
+be unit-tested without touching the filesystem; `load`/`save` are thin
+wrappers around those that do the actual file I/O.
+*/
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Fewest-guesses-to-win per player name.
+pub struct Leaderboard {
+    best_scores: HashMap<String, u32>,
+}
+
+impl Leaderboard {
+    /// Loads a leaderboard from `path`, or starts an empty one if the file
+    /// doesn't exist yet (e.g. on a player's first run).
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Leaderboard> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Leaderboard::parse(&contents)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Leaderboard::new()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Writes the leaderboard to `path` so scores survive restarts.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.serialize())
+    }
+
+    fn new() -> Leaderboard {
+        Leaderboard {
+            best_scores: HashMap::new(),
+        }
+    }
+
+    /// Parses the `name:score` per-line format written by `serialize`.
+    fn parse(contents: &str) -> Leaderboard {
+        let mut best_scores = HashMap::new();
+
+        for line in contents.lines() {
+            if let Some((name, score)) = line.rsplit_once(':') {
+                if let Ok(score) = score.trim().parse() {
+                    best_scores.insert(name.trim().to_string(), score);
+                }
+            }
+        }
+
+        Leaderboard { best_scores }
+    }
+
+    fn serialize(&self) -> String {
+        let mut lines: Vec<String> = self
+            .best_scores
+            .iter()
+            .map(|(name, score)| format!("{}:{}", name, score))
+            .collect();
+        lines.sort();
+        lines.join("\n")
+    }
+
+    /// The fewest guesses `name` has ever won in, if they've won before.
+    pub fn best(&self, name: &str) -> Option<u32> {
+        self.best_scores.get(name).copied()
+    }
+
+    /// Records a win of `attempts` guesses for `name`, keeping the lower
+    /// score if they already had one. Returns `true` if this is a new
+    /// personal best.
+    pub fn record(&mut self, name: &str, attempts: u32) -> bool {
+        let is_new_best = match self.best_scores.get(name) {
+            Some(&best) => attempts < best,
+            None => true,
+        };
+
+        if is_new_best {
+            self.best_scores.insert(name.to_string(), attempts);
+        }
+
+        is_new_best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_player_has_no_best_score() {
+        let leaderboard = Leaderboard::new();
+
+        assert_eq!(leaderboard.best("chaz"), None);
+    }
+
+    #[test]
+    fn recording_first_win_is_a_new_best() {
+        let mut leaderboard = Leaderboard::new();
+
+        assert!(leaderboard.record("chaz", 7));
+        assert_eq!(leaderboard.best("chaz"), Some(7));
+    }
+
+    #[test]
+    fn recording_a_worse_score_keeps_the_old_best() {
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.record("chaz", 5);
+
+        assert!(!leaderboard.record("chaz", 8));
+        assert_eq!(leaderboard.best("chaz"), Some(5));
+    }
+
+    #[test]
+    fn recording_a_better_score_replaces_the_old_best() {
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.record("chaz", 8);
+
+        assert!(leaderboard.record("chaz", 5));
+        assert_eq!(leaderboard.best("chaz"), Some(5));
+    }
+
+    #[test]
+    fn name_containing_a_colon_round_trips() {
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.record("a:b", 5);
+
+        let round_tripped = Leaderboard::parse(&leaderboard.serialize());
+
+        assert_eq!(round_tripped.best("a:b"), Some(5));
+    }
+
+    #[test]
+    fn parse_and_serialize_round_trip() {
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.record("chaz", 5);
+        leaderboard.record("ferris", 3);
+
+        let round_tripped = Leaderboard::parse(&leaderboard.serialize());
+
+        assert_eq!(round_tripped.best("chaz"), Some(5));
+        assert_eq!(round_tripped.best("ferris"), Some(3));
+    }
+}
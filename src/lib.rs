@@ -0,0 +1,312 @@
+/*
+This module pulls the core guessing-game logic out of `main.rs` and into a
+library crate, following the book's "Separating Modules into Different
+Files" chapter. Keeping `Game` and `GuessOutcome` here means they can be
+unit-tested directly, without going through stdin, which `main()` can't
+easily be.
+*/
+use rand::Rng;
+use std::cmp::Ordering;
+
+mod leaderboard;
+pub use leaderboard::Leaderboard;
+
+/// A difficulty level, which caps how many guesses the player gets.
+///
+/// The cap is enforced by the caller (see `main()`'s loop); `Difficulty`
+/// just knows how to turn a level's name into that cap.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Parses a `--difficulty` value, e.g. `"easy"`.
+    pub fn parse(s: &str) -> Result<Difficulty, String> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            other => Err(format!(
+                "unknown difficulty '{}' (expected easy, normal, or hard)",
+                other
+            )),
+        }
+    }
+
+    /// The number of guesses allowed at this difficulty.
+    pub fn max_attempts(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 15,
+            Difficulty::Normal => 10,
+            Difficulty::Hard => 5,
+        }
+    }
+}
+
+/// The fully-parsed command-line configuration for a game.
+pub struct Config {
+    pub min: u32,
+    pub max: u32,
+    pub difficulty: Difficulty,
+}
+
+impl Config {
+    /// Parses `--min`, `--max`, and `--difficulty` out of `args` (which
+    /// should NOT include the program name), applying the book's defaults
+    /// of `1` and `100` when a flag is omitted.
+    pub fn parse<I: Iterator<Item = String>>(args: I) -> Result<Config, String> {
+        let mut min = 1;
+        let mut max = 100;
+        let mut difficulty = Difficulty::Normal;
+
+        let args: Vec<String> = args.collect();
+        let mut i = 0;
+        while i < args.len() {
+            let flag = &args[i];
+            let value = args.get(i + 1).ok_or_else(|| format!("{} needs a value", flag))?;
+
+            match flag.as_str() {
+                "--min" => {
+                    min = value
+                        .parse()
+                        .map_err(|_| format!("--min must be a number, got '{}'", value))?
+                }
+                "--max" => {
+                    max = value
+                        .parse()
+                        .map_err(|_| format!("--max must be a number, got '{}'", value))?
+                }
+                "--difficulty" => difficulty = Difficulty::parse(value)?,
+                other => return Err(format!("unknown argument '{}'", other)),
+            }
+
+            i += 2;
+        }
+
+        if min >= max {
+            return Err(format!("--min ({}) must be less than --max ({})", min, max));
+        }
+
+        // Game::new() draws from low..=high via `gen_range(low, high + 1)`,
+        // so high must leave room for that + 1 without overflowing u32.
+        if max == u32::MAX {
+            return Err(format!("--max must be less than {}", u32::MAX));
+        }
+
+        Ok(Config {
+            min,
+            max,
+            difficulty,
+        })
+    }
+}
+
+/// The result of comparing a player's guess against the secret number.
+///
+/// `main()` matches on this to decide what to print and whether to keep
+/// looping, so all of the comparison logic lives in one place instead of
+/// being duplicated between the library and the binary.
+#[derive(Debug, PartialEq, Eq)]
+pub enum GuessOutcome {
+    TooSmall,
+    TooBig,
+    Correct,
+    InvalidInput,
+    OutOfRange,
+}
+
+/// Holds the secret number and the bounds it was drawn from.
+pub struct Game {
+    secret_number: u32,
+    low: u32,
+    high: u32,
+}
+
+impl Game {
+    /// Creates a new game with a secret number drawn from `low..=high`.
+    pub fn new(low: u32, high: u32) -> Game {
+        let secret_number = rand::thread_rng().gen_range(low, high + 1);
+
+        Game {
+            secret_number,
+            low,
+            high,
+        }
+    }
+
+    pub fn low(&self) -> u32 {
+        self.low
+    }
+
+    pub fn high(&self) -> u32 {
+        self.high
+    }
+
+    /// Parses `input` as a `u32` and compares it to the secret number.
+    ///
+    /// Non-numeric input yields `GuessOutcome::InvalidInput`, and a number
+    /// outside `low..=high` yields `GuessOutcome::OutOfRange`, instead of
+    /// panicking or silently comparing anyway, so callers (namely
+    /// `main()`'s I/O loop) can decide how to report it and whether it
+    /// should count as an attempt.
+    pub fn guess(&mut self, input: &str) -> GuessOutcome {
+        let guess: u32 = match input.trim().parse() {
+            Ok(num) => num,
+            Err(_) => return GuessOutcome::InvalidInput,
+        };
+
+        if guess < self.low || guess > self.high {
+            return GuessOutcome::OutOfRange;
+        }
+
+        match guess.cmp(&self.secret_number) {
+            Ordering::Less => GuessOutcome::TooSmall,
+            Ordering::Greater => GuessOutcome::TooBig,
+            Ordering::Equal => GuessOutcome::Correct,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guess_below_secret_is_too_small() {
+        let mut game = Game {
+            secret_number: 50,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("49"), GuessOutcome::TooSmall);
+    }
+
+    #[test]
+    fn guess_above_secret_is_too_big() {
+        let mut game = Game {
+            secret_number: 50,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("51"), GuessOutcome::TooBig);
+    }
+
+    #[test]
+    fn guess_matching_secret_is_correct() {
+        let mut game = Game {
+            secret_number: 50,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("50"), GuessOutcome::Correct);
+    }
+
+    #[test]
+    fn guess_at_lower_bound_is_correct() {
+        let mut game = Game {
+            secret_number: 1,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("1"), GuessOutcome::Correct);
+    }
+
+    #[test]
+    fn guess_at_upper_bound_is_correct() {
+        let mut game = Game {
+            secret_number: 100,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("100"), GuessOutcome::Correct);
+    }
+
+    #[test]
+    fn guess_below_low_is_out_of_range() {
+        let mut game = Game {
+            secret_number: 50,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("0"), GuessOutcome::OutOfRange);
+    }
+
+    #[test]
+    fn guess_above_high_is_out_of_range() {
+        let mut game = Game {
+            secret_number: 50,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("101"), GuessOutcome::OutOfRange);
+    }
+
+    #[test]
+    fn non_numeric_guess_is_invalid_input() {
+        let mut game = Game {
+            secret_number: 50,
+            low: 1,
+            high: 100,
+        };
+
+        assert_eq!(game.guess("not a number"), GuessOutcome::InvalidInput);
+    }
+
+    fn args(strs: &[&str]) -> impl Iterator<Item = String> {
+        strs.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[test]
+    fn config_defaults_to_one_through_one_hundred_normal() {
+        let config = Config::parse(args(&[])).unwrap();
+
+        assert_eq!(config.min, 1);
+        assert_eq!(config.max, 100);
+        assert_eq!(config.difficulty, Difficulty::Normal);
+    }
+
+    #[test]
+    fn config_parses_min_max_and_difficulty() {
+        let config = Config::parse(args(&["--min", "5", "--max", "10", "--difficulty", "hard"]))
+            .unwrap();
+
+        assert_eq!(config.min, 5);
+        assert_eq!(config.max, 10);
+        assert_eq!(config.difficulty, Difficulty::Hard);
+    }
+
+    #[test]
+    fn config_rejects_min_not_less_than_max() {
+        assert!(Config::parse(args(&["--min", "10", "--max", "10"])).is_err());
+        assert!(Config::parse(args(&["--min", "11", "--max", "10"])).is_err());
+    }
+
+    #[test]
+    fn config_rejects_max_of_u32_max() {
+        let max = u32::MAX.to_string();
+
+        assert!(Config::parse(args(&["--min", "1", "--max", &max])).is_err());
+    }
+
+    #[test]
+    fn config_rejects_unknown_difficulty() {
+        assert!(Config::parse(args(&["--difficulty", "brutal"])).is_err());
+    }
+
+    #[test]
+    fn difficulty_max_attempts() {
+        assert_eq!(Difficulty::Easy.max_attempts(), 15);
+        assert_eq!(Difficulty::Normal.max_attempts(), 10);
+        assert_eq!(Difficulty::Hard.max_attempts(), 5);
+    }
+}